@@ -0,0 +1,272 @@
+use std::error::Error;
+use std::fmt;
+
+pub mod support;
+pub mod theme;
+
+pub use support::ColorSupport;
+pub use theme::{Theme, ThemeError};
+
+/// Represents an RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    r: u8,  // Red component (0-255)
+    g: u8,  // Green component (0-255)
+    b: u8,  // Blue component (0-255)
+}
+
+/// An error returned when a hex color string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorParseError(String);
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex color: {}", self.0)
+    }
+}
+
+impl Error for ColorParseError {}
+
+impl Color {
+    /// Creates a new Color instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - Red component (0-255)
+    /// * `g` - Green component (0-255)
+    /// * `b` - Blue component (0-255)
+    ///
+    /// # Returns
+    ///
+    /// A new Color instance.
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    /// Parses a hex color string into a [`Color`].
+    ///
+    /// Accepts an optional `#` or `0x` prefix, and either the 6-digit
+    /// `rrggbb` form or the shorthand 3-digit `rgb` form (e.g. `abc` expands
+    /// to `aabbcc`), matching the notation used in Alacritty-style palette
+    /// files.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - The hex string to parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Color`], or a [`ColorParseError`] if `hex` is not a
+    /// valid 3- or 6-digit hex triple.
+    pub fn from_hex(hex: &str) -> Result<Color, ColorParseError> {
+        let digits = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .or_else(|| hex.strip_prefix('#'))
+            .unwrap_or(hex);
+
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ColorParseError(hex.to_string()));
+        }
+
+        let expanded;
+        let digits = match digits.len() {
+            6 => digits,
+            3 => {
+                expanded = digits.chars().flat_map(|c| [c, c]).collect::<String>();
+                expanded.as_str()
+            }
+            _ => return Err(ColorParseError(hex.to_string())),
+        };
+
+        let component = |s: &str| {
+            u8::from_str_radix(s, 16).map_err(|_| ColorParseError(hex.to_string()))
+        };
+
+        Ok(Color::new(
+            component(&digits[0..2])?,
+            component(&digits[2..4])?,
+            component(&digits[4..6])?,
+        ))
+    }
+
+    /// Returns the RGB components as a tuple.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the (red, green, blue) components.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Converts the Color to a 256-color code.
+    ///
+    /// # Returns
+    ///
+    /// A u8 representing the closest 256-color code.
+    pub fn to_256_color(&self) -> u8 {
+        let (r, g, b) = self.rgb();
+        16 + 36 * (r as u16 * 5 / 255) as u8 + 6 * (g as u16 * 5 / 255) as u8 + (b as u16 * 5 / 255) as u8
+    }
+
+    /// Maps this color to the nearest of the 16 standard ANSI colors.
+    ///
+    /// Picks the closest match by Euclidean distance in RGB space against
+    /// the classic xterm palette.
+    ///
+    /// # Returns
+    ///
+    /// The SGR foreground code of the nearest color (`30`-`37` for the
+    /// normal colors, `90`-`97` for the bright ones).
+    pub fn to_ansi16(&self) -> u8 {
+        const PALETTE: [(u8, u8, u8, u8); 16] = [
+            (0x00, 0x00, 0x00, 30), // black
+            (0xcd, 0x00, 0x00, 31), // red
+            (0x00, 0xcd, 0x00, 32), // green
+            (0xcd, 0xcd, 0x00, 33), // yellow
+            (0x00, 0x00, 0xee, 34), // blue
+            (0xcd, 0x00, 0xcd, 35), // magenta
+            (0x00, 0xcd, 0xcd, 36), // cyan
+            (0xe5, 0xe5, 0xe5, 37), // white
+            (0x7f, 0x7f, 0x7f, 90), // bright black
+            (0xff, 0x00, 0x00, 91), // bright red
+            (0x00, 0xff, 0x00, 92), // bright green
+            (0xff, 0xff, 0x00, 93), // bright yellow
+            (0x5c, 0x5c, 0xff, 94), // bright blue
+            (0xff, 0x00, 0xff, 95), // bright magenta
+            (0x00, 0xff, 0xff, 96), // bright cyan
+            (0xff, 0xff, 0xff, 97), // bright white
+        ];
+
+        let (r, g, b) = self.rgb();
+        PALETTE
+            .iter()
+            .min_by_key(|&&(pr, pg, pb, _)| {
+                let dr = r as i32 - pr as i32;
+                let dg = g as i32 - pg as i32;
+                let db = b as i32 - pb as i32;
+                dr * dr + dg * dg + db * db
+            })
+            .map(|&(_, _, _, code)| code)
+            .expect("palette is non-empty")
+    }
+
+    /// Renders this color as the best escape sequence a terminal with the
+    /// given [`ColorSupport`] can handle, downgrading truecolor to 256- or
+    /// 16-color as needed and producing no output at all for `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `support` - The color capability to render for.
+    ///
+    /// # Returns
+    ///
+    /// The ANSI escape sequence for this color, or an empty string when
+    /// `support` is [`ColorSupport::None`].
+    pub fn render(&self, support: ColorSupport) -> String {
+        match self.fg_sgr(support) {
+            Some(code) => format!("\x1B[{code}m"),
+            None => String::new(),
+        }
+    }
+
+    /// The raw foreground SGR code for this color at the given support
+    /// level (no surrounding `\x1B[...m` escape), or `None` for
+    /// [`ColorSupport::None`].
+    pub(crate) fn fg_sgr(&self, support: ColorSupport) -> Option<String> {
+        match support {
+            ColorSupport::TrueColor => Some(format!("38;2;{};{};{}", self.r, self.g, self.b)),
+            ColorSupport::Ansi256 => Some(format!("38;5;{}", self.to_256_color())),
+            ColorSupport::Ansi16 => Some(self.to_ansi16().to_string()),
+            ColorSupport::None => None,
+        }
+    }
+
+    /// The raw background SGR code for this color at the given support
+    /// level (no surrounding `\x1B[...m` escape), or `None` for
+    /// [`ColorSupport::None`].
+    pub(crate) fn bg_sgr(&self, support: ColorSupport) -> Option<String> {
+        match support {
+            ColorSupport::TrueColor => Some(format!("48;2;{};{};{}", self.r, self.g, self.b)),
+            ColorSupport::Ansi256 => Some(format!("48;5;{}", self.to_256_color())),
+            ColorSupport::Ansi16 => Some((self.to_ansi16() + 10).to_string()),
+            ColorSupport::None => None,
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the Color as an ANSI escape sequence for terminal output.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\x1B[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+}
+
+// Predefined color constants
+pub const RED: Color = Color { r: 255, g: 0, b: 0 };
+pub const GREEN: Color = Color { r: 0, g: 255, b: 0 };
+pub const BLUE: Color = Color { r: 0, g: 0, b: 255 };
+pub const YELLOW: Color = Color { r: 255, g: 255, b: 0 };
+pub const MAGENTA: Color = Color { r: 255, g: 0, b: 255 };
+pub const CYAN: Color = Color { r: 0, g: 255, b: 255 };
+pub const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+
+/// ANSI escape sequence to reset text formatting.
+pub const RESET: &str = "\x1B[0m";
+
+/// Generates a color gradient between two colors.
+///
+/// # Arguments
+///
+/// * `start` - The starting color of the gradient
+/// * `end` - The ending color of the gradient
+/// * `steps` - The number of color steps in the gradient
+///
+/// # Returns
+///
+/// A vector of Colors representing the gradient from start to end.
+pub fn fade_color(start: &Color, end: &Color, steps: usize) -> Vec<Color> {
+    let (r1, g1, b1) = start.rgb();
+    let (r2, g2, b2) = end.rgb();
+    
+    (0..steps).map(|i| {
+        let t = i as f32 / (steps - 1) as f32;
+        let r = (r1 as f32 * (1.0 - t) + r2 as f32 * t) as u8;
+        let g = (g1 as f32 * (1.0 - t) + g2 as f32 * t) as u8;
+        let b = (b1 as f32 * (1.0 - t) + b2 as f32 * t) as u8;
+        Color::new(r, g, b)
+    }).collect()
+}
+
+/// Returns a random aesthetically pleasing color.
+///
+/// # Returns
+///
+/// A random Color instance.
+pub fn random_pleasing_color() -> Color {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let hue = rng.gen_range(0..360) as f32;
+    let saturation = rng.gen_range(70..100) as f32 / 100.0;
+    let value = rng.gen_range(70..100) as f32 / 100.0;
+    
+    let c = value * saturation;
+    let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = value - c;
+
+    let (r, g, b) = match (hue as u16) / 60 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Color::new(
+        ((r + m) * 255.0) as u8,
+        ((g + m) * 255.0) as u8,
+        ((b + m) * 255.0) as u8,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::env;
+use std::io::IsTerminal;
+
+/// The level of color a terminal is able to render.
+///
+/// Detected from the environment via [`ColorSupport::detect`] so that
+/// printing functions can degrade gracefully instead of always emitting
+/// 24-bit escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit `38;2;r;g;b` sequences.
+    TrueColor,
+    /// 256-color palette (`38;5;n`).
+    Ansi256,
+    /// The 16 standard/bright SGR colors (30-37 / 90-97).
+    Ansi16,
+    /// No color output at all.
+    None,
+}
+
+impl ColorSupport {
+    /// Detects the color support of the current process's stdout.
+    ///
+    /// Honors `NO_COLOR` (disables color unconditionally), falls back to
+    /// `None` when stdout is not a tty, and otherwise inspects `COLORTERM`
+    /// and `TERM` the way most terminal apps do.
+    ///
+    /// # Returns
+    ///
+    /// The detected [`ColorSupport`] level.
+    pub fn detect() -> ColorSupport {
+        if env::var_os("NO_COLOR").is_some() {
+            return ColorSupport::None;
+        }
+        if !std::io::stdout().is_terminal() {
+            return ColorSupport::None;
+        }
+
+        if let Ok(colorterm) = env::var("COLORTERM") {
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return ColorSupport::TrueColor;
+            }
+        }
+
+        match env::var("TERM") {
+            Ok(term) if term == "dumb" => ColorSupport::None,
+            Ok(term) if term.contains("256color") => ColorSupport::Ansi256,
+            Ok(_) => ColorSupport::Ansi16,
+            Err(_) => ColorSupport::Ansi16,
+        }
+    }
+
+    /// The reset sequence to pair with [`Color::render`], or an empty
+    /// string when this support level means nothing was emitted to reset.
+    ///
+    /// [`Color::render`]: super::Color::render
+    pub fn reset_code(&self) -> &'static str {
+        match self {
+            ColorSupport::None => "",
+            _ => super::RESET,
+        }
+    }
+}
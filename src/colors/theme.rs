@@ -0,0 +1,238 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::Color;
+
+/// A named palette of 16 ANSI colors plus primary background/foreground,
+/// in the spirit of an Alacritty `colors:` section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub background: Color,
+    pub foreground: Color,
+    /// The 8 normal colors, in `black, red, green, yellow, blue, magenta, cyan, white` order.
+    pub normal: [Color; 8],
+    /// The 8 bright colors, in the same order as `normal`.
+    pub bright: [Color; 8],
+}
+
+/// An error returned when a theme file could not be loaded or parsed.
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(io::Error),
+    InvalidColor(super::ColorParseError),
+    MissingField(&'static str),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "failed to read theme file: {e}"),
+            ThemeError::InvalidColor(e) => write!(f, "{e}"),
+            ThemeError::MissingField(field) => write!(f, "theme file is missing `{field}`"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<io::Error> for ThemeError {
+    fn from(e: io::Error) -> Self {
+        ThemeError::Io(e)
+    }
+}
+
+impl From<super::ColorParseError> for ThemeError {
+    fn from(e: super::ColorParseError) -> Self {
+        ThemeError::InvalidColor(e)
+    }
+}
+
+const SLOT_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl Theme {
+    /// Loads a [`Theme`] from a palette file.
+    ///
+    /// The expected format is a simplified, line-based take on Alacritty's
+    /// `colors:` section: one `key = value` pair per line, grouped under
+    /// `[primary]`, `[normal]` and `[bright]` section headers, e.g.
+    ///
+    /// ```text
+    /// [primary]
+    /// background = "0x002b36"
+    /// foreground = "#839496"
+    ///
+    /// [normal]
+    /// black = "0x073642"
+    /// red = "0xdc322f"
+    /// ...
+    ///
+    /// [bright]
+    /// black = "0x002b36"
+    /// ...
+    /// ```
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the theme file to load.
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Theme`], or a [`ThemeError`] if the file could not be
+    /// read or is missing required fields.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Theme, ThemeError> {
+        let contents = fs::read_to_string(path)?;
+
+        let mut background = None;
+        let mut foreground = None;
+        let mut normal: [Option<Color>; 8] = [None; 8];
+        let mut bright: [Option<Color>; 8] = [None; 8];
+        let mut section = "";
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                section = match name {
+                    "primary" | "normal" | "bright" => name,
+                    _ => section,
+                };
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = value.trim().trim_matches(['"', '\'']);
+            let color = Color::from_hex(value)?;
+
+            match section {
+                "primary" => match key {
+                    "background" => background = Some(color),
+                    "foreground" => foreground = Some(color),
+                    _ => {}
+                },
+                "normal" => {
+                    if let Some(i) = SLOT_NAMES.iter().position(|&n| n == key) {
+                        normal[i] = Some(color);
+                    }
+                }
+                "bright" => {
+                    if let Some(i) = SLOT_NAMES.iter().position(|&n| n == key) {
+                        bright[i] = Some(color);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let fill = |slots: [Option<Color>; 8], which: &'static str| -> Result<[Color; 8], ThemeError> {
+            let mut out = [Color::new(0, 0, 0); 8];
+            for (i, slot) in slots.into_iter().enumerate() {
+                out[i] = slot.ok_or(ThemeError::MissingField(which))?;
+            }
+            Ok(out)
+        };
+
+        Ok(Theme {
+            background: background.ok_or(ThemeError::MissingField("primary.background"))?,
+            foreground: foreground.ok_or(ThemeError::MissingField("primary.foreground"))?,
+            normal: fill(normal, "normal")?,
+            bright: fill(bright, "bright")?,
+        })
+    }
+
+    /// Resolves a named color slot (e.g. `"red"`, `"bright_blue"`,
+    /// `"background"`, `"foreground"`) to its [`Color`] in this theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The slot name to look up.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Color)` if `name` names a known slot, `None` otherwise.
+    pub fn resolve(&self, name: &str) -> Option<Color> {
+        match name {
+            "background" => return Some(self.background),
+            "foreground" => return Some(self.foreground),
+            _ => {}
+        }
+
+        if let Some(base) = name.strip_prefix("bright_") {
+            return SLOT_NAMES
+                .iter()
+                .position(|&n| n == base)
+                .map(|i| self.bright[i]);
+        }
+
+        SLOT_NAMES
+            .iter()
+            .position(|&n| n == name)
+            .map(|i| self.normal[i])
+    }
+
+    /// The built-in Solarized Dark palette.
+    pub fn solarized_dark() -> Theme {
+        Theme {
+            background: Color::new(0x00, 0x2b, 0x36),
+            foreground: Color::new(0x83, 0x94, 0x96),
+            normal: [
+                Color::new(0x07, 0x36, 0x42),
+                Color::new(0xdc, 0x32, 0x2f),
+                Color::new(0x85, 0x99, 0x00),
+                Color::new(0xb5, 0x89, 0x00),
+                Color::new(0x26, 0x8b, 0xd2),
+                Color::new(0xd3, 0x36, 0x82),
+                Color::new(0x2a, 0xa1, 0x98),
+                Color::new(0xee, 0xe8, 0xd5),
+            ],
+            bright: [
+                Color::new(0x00, 0x2b, 0x36),
+                Color::new(0xcb, 0x4b, 0x16),
+                Color::new(0x58, 0x6e, 0x75),
+                Color::new(0x65, 0x7b, 0x83),
+                Color::new(0x83, 0x94, 0x96),
+                Color::new(0x6c, 0x71, 0xc4),
+                Color::new(0x93, 0xa1, 0xa1),
+                Color::new(0xfd, 0xf6, 0xe3),
+            ],
+        }
+    }
+
+    /// The built-in Tomorrow Night palette.
+    pub fn tomorrow_night() -> Theme {
+        Theme {
+            background: Color::new(0x1d, 0x1f, 0x21),
+            foreground: Color::new(0xc5, 0xc8, 0xc6),
+            normal: [
+                Color::new(0x1d, 0x1f, 0x21),
+                Color::new(0xcc, 0x66, 0x66),
+                Color::new(0xb5, 0xbd, 0x68),
+                Color::new(0xf0, 0xc6, 0x74),
+                Color::new(0x81, 0xa2, 0xbe),
+                Color::new(0xb2, 0x94, 0xbb),
+                Color::new(0x8a, 0xbe, 0xb7),
+                Color::new(0xc5, 0xc8, 0xc6),
+            ],
+            bright: [
+                Color::new(0x96, 0x98, 0x96),
+                Color::new(0xd5, 0x4e, 0x53),
+                Color::new(0xb9, 0xca, 0x4a),
+                Color::new(0xe7, 0xc5, 0x47),
+                Color::new(0x7a, 0xa6, 0xda),
+                Color::new(0xc3, 0x97, 0xd8),
+                Color::new(0x70, 0xc0, 0xb1),
+                Color::new(0xea, 0xea, 0xea),
+            ],
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::colors::{Color, RESET};
+use crate::colors::{Color, ColorSupport};
 use rand::Rng;
 use std::io::{self, Write};
 use std::thread;
@@ -23,60 +23,92 @@ impl Default for EffectSettings {
     }
 }
 
-/// Displays text with a typewriter effect.
+/// Writes text to `w` with a typewriter effect.
 ///
 /// # Arguments
 ///
+/// * `w` - The writer to print to
 /// * `text` - The text to display
 /// * `settings` - EffectSettings for customization
 /// * `color` - Optional color for the text
-pub fn typewriter(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+pub fn typewriter_to<W: Write>(w: &mut W, text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    let support = ColorSupport::detect();
     for c in text.chars() {
         if let Some(col) = color {
-            print!("{col}{c}");
+            write!(w, "{}{c}", col.render(support)).unwrap();
         } else {
-            print!("{c}");
+            write!(w, "{c}").unwrap();
         }
-        io::stdout().flush().unwrap();
+        w.flush().unwrap();
         thread::sleep(Duration::from_millis(settings.delay));
     }
     if color.is_some() {
-        print!("{RESET}");
+        write!(w, "{}", support.reset_code()).unwrap();
     }
-    io::stdout().flush().unwrap();
+    w.flush().unwrap();
 }
 
-/// Displays a loading bar effect.
+/// Displays text with a typewriter effect.
+///
+/// # Arguments
+///
+/// * `text` - The text to display
+/// * `settings` - EffectSettings for customization
+/// * `color` - Optional color for the text
+pub fn typewriter(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    typewriter_to(&mut io::stdout(), text, settings, color);
+}
+
+/// Writes a loading bar effect to `w`.
 ///
 /// # Arguments
 ///
+/// * `w` - The writer to print to
 /// * `total` - Total number of steps in the loading process
 /// * `settings` - EffectSettings for customization
 /// * `color` - Color for the loading bar
-pub fn loading_bar(total: usize, settings: &EffectSettings, color: &Color) {
+pub fn loading_bar_to<W: Write>(w: &mut W, total: usize, settings: &EffectSettings, color: &Color) {
+    let support = ColorSupport::detect();
+    let rendered = color.render(support);
+    let reset = support.reset_code();
     for i in 0..=total {
         let progress = (i as f32 / total as f32 * settings.width as f32) as usize;
-        print!(
-            "\r{color}[{:▓>progress$}{:░>remaining$}] {i}/{total}{RESET}",
+        write!(
+            w,
+            "\r{rendered}[{:▓>progress$}{:░>remaining$}] {i}/{total}{reset}",
             "",
             "",
             progress = progress,
             remaining = settings.width - progress
-        );
-        io::stdout().flush().unwrap();
+        )
+        .unwrap();
+        w.flush().unwrap();
         thread::sleep(Duration::from_millis(settings.delay));
     }
-    println!();
+    writeln!(w).unwrap();
 }
 
-/// Displays text with a wiggle effect.
+/// Displays a loading bar effect.
+///
+/// # Arguments
+///
+/// * `total` - Total number of steps in the loading process
+/// * `settings` - EffectSettings for customization
+/// * `color` - Color for the loading bar
+pub fn loading_bar(total: usize, settings: &EffectSettings, color: &Color) {
+    loading_bar_to(&mut io::stdout(), total, settings, color);
+}
+
+/// Writes text to `w` with a wiggle effect.
 ///
 /// # Arguments
 ///
+/// * `w` - The writer to print to
 /// * `text` - The text to display
 /// * `settings` - EffectSettings for customization
 /// * `color` - Optional color for the text
-pub fn wiggle(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+pub fn wiggle_to<W: Write>(w: &mut W, text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    let support = ColorSupport::detect();
     let chars: Vec<char> = text.chars().collect();
     let len = chars.len();
 
@@ -91,30 +123,43 @@ pub fn wiggle(text: &str, settings: &EffectSettings, color: Option<&Color>) {
                 }
             }
             if let Some(col) = color {
-                print!("\r{col}{line}");
+                write!(w, "\r{}{line}", col.render(support)).unwrap();
             } else {
-                print!("\r{line}");
+                write!(w, "\r{line}").unwrap();
             }
-            io::stdout().flush().unwrap();
+            w.flush().unwrap();
             thread::sleep(Duration::from_millis(settings.delay));
         }
     }
 
     if color.is_some() {
-        print!("{RESET}");
+        write!(w, "{}", support.reset_code()).unwrap();
     }
-    println!();
-    io::stdout().flush().unwrap();
+    writeln!(w).unwrap();
+    w.flush().unwrap();
 }
 
-/// Displays text with a matrix-like decoding effect.
+/// Displays text with a wiggle effect.
 ///
 /// # Arguments
 ///
 /// * `text` - The text to display
 /// * `settings` - EffectSettings for customization
 /// * `color` - Optional color for the text
-pub fn matrix_effect(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+pub fn wiggle(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    wiggle_to(&mut io::stdout(), text, settings, color);
+}
+
+/// Writes text to `w` with a matrix-like decoding effect.
+///
+/// # Arguments
+///
+/// * `w` - The writer to print to
+/// * `text` - The text to display
+/// * `settings` - EffectSettings for customization
+/// * `color` - Optional color for the text
+pub fn matrix_effect_to<W: Write>(w: &mut W, text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    let support = ColorSupport::detect();
     let mut rng = rand::thread_rng();
     let chars: Vec<char> = text.chars().collect();
     let symbols = "!@#$%^&*()_+-=[]{}|;:,.<>?";
@@ -135,29 +180,42 @@ pub fn matrix_effect(text: &str, settings: &EffectSettings, color: Option<&Color
                 }
             }
             if let Some(col) = color {
-                print!("\r{col}{line}");
+                write!(w, "\r{}{line}", col.render(support)).unwrap();
             } else {
-                print!("\r{line}");
+                write!(w, "\r{line}").unwrap();
             }
-            io::stdout().flush().unwrap();
+            w.flush().unwrap();
             thread::sleep(Duration::from_millis(settings.delay));
         }
     }
 
     if color.is_some() {
-        print!("{RESET}");
+        write!(w, "{}", support.reset_code()).unwrap();
     }
-    println!();
-    io::stdout().flush().unwrap();
+    writeln!(w).unwrap();
+    w.flush().unwrap();
 }
 
-/// Displays text with a rainbow effect.
+/// Displays text with a matrix-like decoding effect.
 ///
 /// # Arguments
 ///
 /// * `text` - The text to display
 /// * `settings` - EffectSettings for customization
-pub fn rainbow_text(text: &str, settings: &EffectSettings) {
+/// * `color` - Optional color for the text
+pub fn matrix_effect(text: &str, settings: &EffectSettings, color: Option<&Color>) {
+    matrix_effect_to(&mut io::stdout(), text, settings, color);
+}
+
+/// Writes text to `w` with a rainbow effect.
+///
+/// # Arguments
+///
+/// * `w` - The writer to print to
+/// * `text` - The text to display
+/// * `settings` - EffectSettings for customization
+pub fn rainbow_text_to<W: Write>(w: &mut W, text: &str, settings: &EffectSettings) {
+    let support = ColorSupport::detect();
     let colors = [
         Color::new(255, 0, 0),   // Red
         Color::new(255, 127, 0), // Orange
@@ -173,36 +231,66 @@ pub fn rainbow_text(text: &str, settings: &EffectSettings) {
             let mut colored_text = String::new();
             for (j, c) in text.chars().enumerate() {
                 let color_index = (i + j) % colors.len();
-                colored_text.push_str(&format!("{}{c}", colors[color_index]));
+                colored_text.push_str(&format!("{}{c}", colors[color_index].render(support)));
             }
-            print!("\r{colored_text}{RESET}");
-            io::stdout().flush().unwrap();
+            write!(w, "\r{colored_text}{}", support.reset_code()).unwrap();
+            w.flush().unwrap();
             thread::sleep(Duration::from_millis(settings.delay));
         }
     }
-    println!();
+    writeln!(w).unwrap();
 }
 
-/// Displays a progress spinner with customizable styles.
+/// Displays text with a rainbow effect.
+///
+/// # Arguments
+///
+/// * `text` - The text to display
+/// * `settings` - EffectSettings for customization
+pub fn rainbow_text(text: &str, settings: &EffectSettings) {
+    rainbow_text_to(&mut io::stdout(), text, settings);
+}
+
+/// Writes a progress spinner with customizable styles to `w`.
 ///
 /// # Arguments
 ///
+/// * `w` - The writer to print to
 /// * `total` - Total number of steps in the process
 /// * `settings` - EffectSettings for customization
 /// * `color` - Color for the spinner
 /// * `style` - Style of the spinner (0: default, 1: dots, 2: arrows)
-pub fn progress_spinner(total: usize, settings: &EffectSettings, color: &Color, style: usize) {
+pub fn progress_spinner_to<W: Write>(
+    w: &mut W,
+    total: usize,
+    settings: &EffectSettings,
+    color: &Color,
+    style: usize,
+) {
     let spinner_chars = match style {
         1 => vec!['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'],
         2 => vec!['←', '↖', '↑', '↗', '→', '↘', '↓', '↙'],
         _ => vec!['|', '/', '-', '\\'],
     };
 
+    let support = ColorSupport::detect();
     for i in 0..=total {
         let spinner_char = spinner_chars[i % spinner_chars.len()];
-        print!("\r{color}{spinner_char} {i}/{total}");
-        io::stdout().flush().unwrap();
+        write!(w, "\r{}{spinner_char} {i}/{total}", color.render(support)).unwrap();
+        w.flush().unwrap();
         thread::sleep(Duration::from_millis(settings.delay));
     }
-    println!("{RESET}");
+    writeln!(w, "{}", support.reset_code()).unwrap();
+}
+
+/// Displays a progress spinner with customizable styles.
+///
+/// # Arguments
+///
+/// * `total` - Total number of steps in the process
+/// * `settings` - EffectSettings for customization
+/// * `color` - Color for the spinner
+/// * `style` - Style of the spinner (0: default, 1: dots, 2: arrows)
+pub fn progress_spinner(total: usize, settings: &EffectSettings, color: &Color, style: usize) {
+    progress_spinner_to(&mut io::stdout(), total, settings, color, style);
 }
@@ -1,7 +1,28 @@
-use crate::colors::{Color, RESET};
+use crate::colors::{Color, ColorSupport};
+use crate::width::display_width;
 use std::io::{self, Write};
 use terminal_size::{terminal_size, Width};
 
+/// Pads `text` on the right with spaces up to `width` display columns,
+/// the display-width-aware equivalent of `format!("{:<width$}", text)`.
+fn pad_to(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
+/// Writes colored text without a newline to `w`.
+///
+/// # Arguments
+///
+/// * `w` - The writer to print to
+/// * `text` - The text to print
+/// * `color` - The color to use for the text
+pub fn print_colored_to<W: Write>(w: &mut W, text: &str, color: &Color) {
+    let support = ColorSupport::detect();
+    write!(w, "{}{text}{}", color.render(support), support.reset_code()).unwrap();
+    w.flush().unwrap();
+}
+
 /// Prints colored text without a newline.
 ///
 /// # Arguments
@@ -9,8 +30,20 @@ use terminal_size::{terminal_size, Width};
 /// * `text` - The text to print
 /// * `color` - The color to use for the text
 pub fn print_colored(text: &str, color: &Color) {
-    print!("{color}{text}{RESET}");
-    io::stdout().flush().unwrap();
+    print_colored_to(&mut io::stdout(), text, color);
+}
+
+/// Writes colored text with a newline to `w`.
+///
+/// # Arguments
+///
+/// * `w` - The writer to print to
+/// * `text` - The text to print
+/// * `color` - The color to use for the text
+pub fn println_colored_to<W: Write>(w: &mut W, text: &str, color: &Color) {
+    let support = ColorSupport::detect();
+    writeln!(w, "{}{text}{}", color.render(support), support.reset_code()).unwrap();
+    w.flush().unwrap();
 }
 
 /// Prints colored text with a newline.
@@ -20,26 +53,38 @@ pub fn print_colored(text: &str, color: &Color) {
 /// * `text` - The text to print
 /// * `color` - The color to use for the text
 pub fn println_colored(text: &str, color: &Color) {
-    println!("{color}{text}{RESET}");
+    println_colored_to(&mut io::stdout(), text, color);
 }
 
-/// Prints text with a color gradient effect.
+/// Writes text with a color gradient effect to `w`.
 ///
 /// # Arguments
 ///
+/// * `w` - The writer to print to
 /// * `text` - The text to print
 /// * `colors` - An array of colors to use for the gradient
-pub fn print_fade(text: &str, colors: &[Color]) {
+pub fn print_fade_to<W: Write>(w: &mut W, text: &str, colors: &[Color]) {
+    let support = ColorSupport::detect();
     let chars: Vec<char> = text.chars().collect();
     let color_count = colors.len();
 
     for (i, c) in chars.iter().enumerate() {
         let color_index = (i * color_count) / chars.len();
-        print!("{}{c}", colors[color_index]);
+        write!(w, "{}{c}", colors[color_index].render(support)).unwrap();
     }
 
-    print!("{RESET}");
-    io::stdout().flush().unwrap();
+    write!(w, "{}", support.reset_code()).unwrap();
+    w.flush().unwrap();
+}
+
+/// Prints text with a color gradient effect.
+///
+/// # Arguments
+///
+/// * `text` - The text to print
+/// * `colors` - An array of colors to use for the gradient
+pub fn print_fade(text: &str, colors: &[Color]) {
+    print_fade_to(&mut io::stdout(), text, colors);
 }
 
 /// Centers text based on the terminal width.
@@ -55,8 +100,8 @@ pub fn center_text(text: &str) -> String {
     let width = terminal_size()
         .map(|(Width(w), _)| w as usize)
         .unwrap_or(80);
-    let padding = (width - text.len()) / 2;
-    format!("{:>width$}", text, width = padding + text.len())
+    let padding = width.saturating_sub(display_width(text)) / 2;
+    format!("{}{text}", " ".repeat(padding))
 }
 
 /// Surrounds text with a box made of Unicode box-drawing characters.
@@ -70,7 +115,7 @@ pub fn center_text(text: &str) -> String {
 /// A String containing the text surrounded by a box
 pub fn box_text(text: &str) -> String {
     let lines: Vec<&str> = text.lines().collect();
-    let max_length = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let max_length = lines.iter().map(|line| display_width(line)).max().unwrap_or(0);
     let top_bottom = format!("╔{}╗", "═".repeat(max_length + 2));
     let mut result = String::new();
 
@@ -78,7 +123,7 @@ pub fn box_text(text: &str) -> String {
     result.push('\n');
 
     for line in lines {
-        result.push_str(&format!("║ {:<width$} ║\n", line, width = max_length));
+        result.push_str(&format!("║ {} ║\n", pad_to(line, max_length)));
     }
 
     result.push_str(&top_bottom.replace("╔", "╚").replace("╗", "╝"));
@@ -102,16 +147,17 @@ pub fn create_table(headers: &[&str], rows: &Vec<Vec<String>>, color: Option<&Co
         .enumerate()
         .map(|(i, &header)| {
             rows.iter()
-                .map(|row| row.get(i).map_or(0, |cell| cell.len()))
+                .map(|row| row.get(i).map_or(0, |cell| display_width(cell)))
                 .max()
                 .unwrap_or(0)
-                .max(header.len())
+                .max(display_width(header))
         })
         .collect();
 
+    let support = ColorSupport::detect();
     let mut table = String::new();
-    let color_str = color.map_or_else(String::new, |c| c.to_string());
-    let reset_str = color.map_or_else(String::new, |_| crate::colors::RESET.to_string());
+    let color_str = color.map_or_else(String::new, |c| c.render(support));
+    let reset_str = color.map_or_else(String::new, |_| support.reset_code().to_string());
 
     // Top border
     table.push_str(&format!(
@@ -126,7 +172,7 @@ pub fn create_table(headers: &[&str], rows: &Vec<Vec<String>>, color: Option<&Co
     // Headers
     table.push_str(&format!("{color_str}║ "));
     for (i, header) in headers.iter().enumerate() {
-        table.push_str(&format!("{:<width$} ", header, width = column_widths[i]));
+        table.push_str(&format!("{} ", pad_to(header, column_widths[i])));
         if i < headers.len() - 1 {
             table.push_str("│ ");
         }
@@ -147,7 +193,7 @@ pub fn create_table(headers: &[&str], rows: &Vec<Vec<String>>, color: Option<&Co
     for row in rows {
         table.push_str(&format!("{color_str}║ "));
         for (i, cell) in row.iter().enumerate() {
-            table.push_str(&format!("{:<width$} ", cell, width = column_widths[i]));
+            table.push_str(&format!("{} ", pad_to(cell, column_widths[i])));
             if i < row.len() - 1 {
                 table.push_str("│ ");
             }
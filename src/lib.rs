@@ -2,6 +2,7 @@ pub mod colors;
 pub mod effects;
 pub mod formatting;
 pub mod system;
+pub mod width;
 
 pub mod styles;
 
@@ -9,3 +10,4 @@ pub use colors::*;
 pub use effects::*;
 pub use formatting::*;
 pub use system::*;
+pub use width::*;
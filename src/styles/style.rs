@@ -0,0 +1,172 @@
+use crate::colors::{Color, ColorSupport, RESET};
+use std::io::{self, Write};
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of text attributes (bold, italic, etc.), combinable with `|`.
+///
+/// Mirrors tui's `Modifier` bitflags, minus the external dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modifier(u8);
+
+impl Modifier {
+    pub const NONE: Modifier = Modifier(0);
+    pub const BOLD: Modifier = Modifier(1 << 0);
+    pub const DIM: Modifier = Modifier(1 << 1);
+    pub const ITALIC: Modifier = Modifier(1 << 2);
+    pub const UNDERLINE: Modifier = Modifier(1 << 3);
+    pub const BLINK: Modifier = Modifier(1 << 4);
+    pub const REVERSE: Modifier = Modifier(1 << 5);
+    pub const STRIKETHROUGH: Modifier = Modifier(1 << 6);
+
+    /// Returns `true` if `self` has all of the bits set in `other`.
+    pub fn contains(&self, other: Modifier) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Modifier {
+    type Output = Modifier;
+
+    fn bitor(self, rhs: Modifier) -> Modifier {
+        Modifier(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Modifier {
+    fn bitor_assign(&mut self, rhs: Modifier) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// An optional foreground color, background color, and set of text
+/// modifiers that compose into a single SGR escape sequence.
+///
+/// # Examples
+///
+/// ```ignore
+/// let style = Style::new().fg(RED).bg(BLACK).bold().underline();
+/// print_styled("alert", &style);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    modifiers: Modifier,
+}
+
+impl Default for Modifier {
+    fn default() -> Self {
+        Modifier::NONE
+    }
+}
+
+impl Style {
+    /// Creates an empty [`Style`] with no foreground, background, or
+    /// modifiers set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the foreground color.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Adds the given modifier(s) to this style.
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.modifiers |= modifier;
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.add_modifier(Modifier::BOLD)
+    }
+
+    pub fn dim(self) -> Self {
+        self.add_modifier(Modifier::DIM)
+    }
+
+    pub fn italic(self) -> Self {
+        self.add_modifier(Modifier::ITALIC)
+    }
+
+    pub fn underline(self) -> Self {
+        self.add_modifier(Modifier::UNDERLINE)
+    }
+
+    pub fn blink(self) -> Self {
+        self.add_modifier(Modifier::BLINK)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.add_modifier(Modifier::REVERSE)
+    }
+
+    pub fn strikethrough(self) -> Self {
+        self.add_modifier(Modifier::STRIKETHROUGH)
+    }
+
+    /// Composes the foreground, background, and modifiers into a single
+    /// `\x1B[...m` SGR sequence, downgrading colors to what the current
+    /// terminal supports (see [`ColorSupport`]).
+    ///
+    /// # Returns
+    ///
+    /// The escape sequence, or an empty string if nothing is set.
+    pub fn to_ansi_sequence(&self) -> String {
+        let support = ColorSupport::detect();
+        let mut codes = Vec::new();
+
+        const FLAGS: [(Modifier, &str); 7] = [
+            (Modifier::BOLD, "1"),
+            (Modifier::DIM, "2"),
+            (Modifier::ITALIC, "3"),
+            (Modifier::UNDERLINE, "4"),
+            (Modifier::BLINK, "5"),
+            (Modifier::REVERSE, "7"),
+            (Modifier::STRIKETHROUGH, "9"),
+        ];
+        for (flag, code) in FLAGS {
+            if self.modifiers.contains(flag) {
+                codes.push(code.to_string());
+            }
+        }
+
+        if let Some(fg) = self.fg.and_then(|c| c.fg_sgr(support)) {
+            codes.push(fg);
+        }
+        if let Some(bg) = self.bg.and_then(|c| c.bg_sgr(support)) {
+            codes.push(bg);
+        }
+
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\x1B[{}m", codes.join(";"))
+        }
+    }
+}
+
+/// Prints `text` wrapped in the SGR sequence for `style`, resetting
+/// formatting afterward.
+///
+/// # Arguments
+///
+/// * `text` - The text to print.
+/// * `style` - The style to apply.
+pub fn print_styled(text: &str, style: &Style) {
+    let sequence = style.to_ansi_sequence();
+    if sequence.is_empty() {
+        print!("{text}");
+    } else {
+        print!("{sequence}{text}{RESET}");
+    }
+    io::stdout().flush().unwrap();
+}
@@ -1,10 +1,29 @@
 use std::io::{self, Write};
+use std::sync::Once;
+
+/// Writes the escape codes to clear the terminal screen and move the
+/// cursor to the top-left corner to `w`.
+pub fn clear_screen_to<W: Write>(w: &mut W) {
+    // ANSI escape code to clear screen and move cursor to (1,1)
+    write!(w, "\x1B[2J\x1B[1;1H").unwrap();
+    w.flush().unwrap();
+}
 
 /// Clears the terminal screen and moves the cursor to the top-left corner.
 pub fn clear_screen() {
-    // ANSI escape code to clear screen and move cursor to (1,1)
-    print!("\x1B[2J\x1B[1;1H");
-    io::stdout().flush().unwrap();
+    clear_screen_to(&mut io::stdout());
+}
+
+/// Writes the escape code to set the terminal window title to `w`.
+///
+/// # Arguments
+///
+/// * `w` - The writer to print to
+/// * `title` - The string to set as the terminal window title
+pub fn set_title_to<W: Write>(w: &mut W, title: &str) {
+    // ANSI escape code to set terminal title
+    write!(w, "\x1B]0;{}\x07", title).unwrap();
+    w.flush().unwrap();
 }
 
 /// Sets the terminal window title.
@@ -13,21 +32,179 @@ pub fn clear_screen() {
 ///
 /// * `title` - The string to set as the terminal window title
 pub fn set_title(title: &str) {
-    // ANSI escape code to set terminal title
-    print!("\x1B]0;{}\x07", title);
-    io::stdout().flush().unwrap();
+    set_title_to(&mut io::stdout(), title);
+}
+
+/// Writes the escape code to hide the cursor to `w`.
+pub fn hide_cursor_to<W: Write>(w: &mut W) {
+    // ANSI escape code to hide cursor
+    write!(w, "\x1B[?25l").unwrap();
+    w.flush().unwrap();
 }
 
 /// Hides the cursor in the terminal.
 pub fn hide_cursor() {
-    // ANSI escape code to hide cursor
-    print!("\x1B[?25l");
-    io::stdout().flush().unwrap();
+    hide_cursor_to(&mut io::stdout());
+}
+
+/// Writes the escape code to show the cursor to `w`.
+pub fn show_cursor_to<W: Write>(w: &mut W) {
+    // ANSI escape code to show cursor
+    write!(w, "\x1B[?25h").unwrap();
+    w.flush().unwrap();
 }
 
 /// Shows the cursor in the terminal.
 pub fn show_cursor() {
-    // ANSI escape code to show cursor
-    print!("\x1B[?25h");
-    io::stdout().flush().unwrap();
+    show_cursor_to(&mut io::stdout());
+}
+
+/// Writes the escape code to switch to the alternate screen buffer to `w`,
+/// leaving the main screen's scrollback untouched.
+pub fn enter_alternate_screen_to<W: Write>(w: &mut W) {
+    write!(w, "\x1B[?1049h").unwrap();
+    w.flush().unwrap();
+}
+
+/// Switches to the terminal's alternate screen buffer, leaving the main
+/// screen's scrollback untouched.
+pub fn enter_alternate_screen() {
+    enter_alternate_screen_to(&mut io::stdout());
+}
+
+/// Writes the escape code to leave the alternate screen buffer to `w`,
+/// restoring the main screen.
+pub fn leave_alternate_screen_to<W: Write>(w: &mut W) {
+    write!(w, "\x1B[?1049l").unwrap();
+    w.flush().unwrap();
+}
+
+/// Leaves the alternate screen buffer, restoring the main screen.
+pub fn leave_alternate_screen() {
+    leave_alternate_screen_to(&mut io::stdout());
+}
+
+/// Enables ANSI escape sequence processing on Windows consoles by turning
+/// on `ENABLE_VIRTUAL_TERMINAL_PROCESSING` for stdout. A no-op on other
+/// platforms, where ANSI is already supported. Call this once at startup
+/// before printing any colored output.
+#[cfg(windows)]
+pub fn enable_ansi_support() {
+    use std::os::windows::io::AsRawHandle;
+
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    extern "system" {
+        fn GetConsoleMode(console_handle: isize, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console_handle: isize, mode: u32) -> i32;
+    }
+
+    let handle = io::stdout().as_raw_handle() as isize;
+    unsafe {
+        let mut mode: u32 = 0;
+        if GetConsoleMode(handle, &mut mode) != 0 {
+            SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING);
+        }
+    }
+}
+
+/// Enables ANSI escape sequence processing on Windows consoles. A no-op on
+/// other platforms, where ANSI is already supported.
+#[cfg(not(windows))]
+pub fn enable_ansi_support() {}
+
+/// An RAII guard that restores terminal state on [`Drop`].
+///
+/// `hide_cursor`, `show_cursor`, `clear_screen`, and `set_title` above are
+/// one-shot calls: if an effect panics while the cursor is hidden or the
+/// alternate screen is active, the terminal is left corrupted. Build a
+/// `TerminalGuard` instead so the change is undone automatically, even on
+/// panic.
+///
+/// # Examples
+///
+/// ```ignore
+/// let _guard = TerminalGuard::new().hide_cursor().enter_alternate_screen();
+/// // ... draw a full-screen effect ...
+/// // cursor and main screen are restored when `_guard` drops.
+/// ```
+pub struct TerminalGuard {
+    hid_cursor: bool,
+    entered_alternate_screen: bool,
+    set_title: bool,
+}
+
+impl TerminalGuard {
+    /// Creates a guard that has not changed any terminal state yet.
+    pub fn new() -> Self {
+        Self {
+            hid_cursor: false,
+            entered_alternate_screen: false,
+            set_title: false,
+        }
+    }
+
+    /// Hides the cursor; it is shown again when this guard drops.
+    pub fn hide_cursor(mut self) -> Self {
+        hide_cursor();
+        self.hid_cursor = true;
+        self
+    }
+
+    /// Enters the alternate screen; the main screen is restored when this
+    /// guard drops.
+    pub fn enter_alternate_screen(mut self) -> Self {
+        enter_alternate_screen();
+        self.entered_alternate_screen = true;
+        self
+    }
+
+    /// Sets the terminal title. Since there is no portable way to read
+    /// back the previous title, it is cleared (set to an empty string)
+    /// rather than restored when this guard drops.
+    pub fn set_title(mut self, title: &str) -> Self {
+        set_title(title);
+        self.set_title = true;
+        self
+    }
+}
+
+impl Default for TerminalGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if self.entered_alternate_screen {
+            leave_alternate_screen();
+        }
+        if self.hid_cursor {
+            show_cursor();
+        }
+        if self.set_title {
+            set_title("");
+        }
+    }
+}
+
+static PANIC_HOOK_INSTALLED: Once = Once::new();
+
+/// Installs a panic hook that resets the terminal (shows the cursor and
+/// leaves the alternate screen) before the default panic message prints,
+/// chaining the previously installed hook so other panic handling still
+/// runs. This covers panics that a [`TerminalGuard`] never got the chance
+/// to construct, or ones outside its scope.
+///
+/// Safe to call more than once; only the first call installs the hook.
+pub fn install_panic_hook() {
+    PANIC_HOOK_INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            show_cursor();
+            leave_alternate_screen();
+            previous(info);
+        }));
+    });
 }
\ No newline at end of file
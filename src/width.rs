@@ -0,0 +1,52 @@
+use unicode_width::UnicodeWidthChar;
+
+/// Strips ANSI SGR escape sequences (`\x1B[...m`) from `s`, leaving only
+/// the visible text.
+///
+/// # Arguments
+///
+/// * `s` - The text to strip.
+///
+/// # Returns
+///
+/// A new `String` with all `\x1B[...m` sequences removed.
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1B' && chars.peek() == Some(&'[') {
+            chars.next(); // consume '['
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+/// Computes the true display width of `s` in terminal columns.
+///
+/// ANSI SGR escapes are stripped first (so already-colored cell contents
+/// measure by their visible text only), then each remaining character is
+/// counted using East-Asian-width rules: wide glyphs (e.g. CJK, emoji)
+/// count as 2 columns, zero-width and combining marks count as 0.
+///
+/// # Arguments
+///
+/// * `s` - The text to measure.
+///
+/// # Returns
+///
+/// The number of terminal columns `s` occupies when printed.
+pub fn display_width(s: &str) -> usize {
+    strip_ansi(s)
+        .chars()
+        .map(|c| c.width().unwrap_or(0))
+        .sum()
+}